@@ -0,0 +1,162 @@
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use rand::RngCore;
+
+use crate::hittable::{Hittable, HitRecord, Point3, Ray};
+
+/// An axis-aligned bounding box, stored as its minimum and maximum corners.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy)]
+pub struct AABB {
+    pub min: Point3,
+    pub max: Point3,
+}
+
+impl Default for AABB {
+    fn default() -> Self {
+        AABB::EMPTY
+    }
+}
+
+impl AABB {
+    pub const EMPTY: AABB = AABB {
+        min: Point3::new(f64::INFINITY, f64::INFINITY, f64::INFINITY),
+        max: Point3::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY),
+    };
+
+    pub fn new(min: Point3, max: Point3) -> Self {
+        AABB { min, max }
+    }
+
+    /// Ensures no axis has zero thickness (important for flat primitives
+    /// like `Quad`, which would otherwise produce a degenerate slab test).
+    pub fn pad(&self) -> AABB {
+        let delta = 0.0001;
+        let pad_axis = |min: f64, max: f64| {
+            if max - min < delta {
+                (min - delta / 2.0, max + delta / 2.0)
+            } else {
+                (min, max)
+            }
+        };
+        let (min_x, max_x) = pad_axis(self.min.x, self.max.x);
+        let (min_y, max_y) = pad_axis(self.min.y, self.max.y);
+        let (min_z, max_z) = pad_axis(self.min.z, self.max.z);
+        AABB::new(Point3::new(min_x, min_y, min_z), Point3::new(max_x, max_y, max_z))
+    }
+
+    pub fn union(&self, other: &AABB) -> AABB {
+        AABB::new(
+            Point3::new(
+                self.min.x.min(other.min.x),
+                self.min.y.min(other.min.y),
+                self.min.z.min(other.min.z),
+            ),
+            Point3::new(
+                self.max.x.max(other.max.x),
+                self.max.y.max(other.max.y),
+                self.max.z.max(other.max.z),
+            ),
+        )
+    }
+
+    pub fn axis_interval(&self, axis: usize) -> (f64, f64) {
+        match axis {
+            0 => (self.min.x, self.max.x),
+            1 => (self.min.y, self.max.y),
+            _ => (self.min.z, self.max.z),
+        }
+    }
+
+    pub fn longest_axis(&self) -> usize {
+        let extents = [
+            self.max.x - self.min.x,
+            self.max.y - self.min.y,
+            self.max.z - self.min.z,
+        ];
+        if extents[0] > extents[1] && extents[0] > extents[2] {
+            0
+        } else if extents[1] > extents[2] {
+            1
+        } else {
+            2
+        }
+    }
+
+    pub fn hit(&self, r: &Ray, mut t_min: f64, mut t_max: f64) -> bool {
+        for axis in 0..3 {
+            let (min, max) = self.axis_interval(axis);
+            let inv_d = 1.0 / r.dir[axis];
+            let mut t0 = (min - r.origin[axis]) * inv_d;
+            let mut t1 = (max - r.origin[axis]) * inv_d;
+            if inv_d < 0.0 {
+                std::mem::swap(&mut t0, &mut t1);
+            }
+            t_min = t_min.max(t0);
+            t_max = t_max.min(t1);
+            if t_max <= t_min {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// A binary bounding-volume hierarchy built once over a flat list of
+/// `Hittable`s, turning an O(n) linear scan into an O(log n) tree descent.
+pub struct BVHNode {
+    left: Arc<dyn Hittable>,
+    right: Arc<dyn Hittable>,
+    bbox: AABB,
+}
+
+impl BVHNode {
+    pub fn new(mut objects: Vec<Arc<dyn Hittable>>) -> Self {
+        let mut bbox = AABB::EMPTY;
+        for object in &objects {
+            bbox = bbox.union(&object.bounding_box());
+        }
+        let axis = bbox.longest_axis();
+
+        let (left, right): (Arc<dyn Hittable>, Arc<dyn Hittable>) = match objects.len() {
+            1 => (objects[0].clone(), objects[0].clone()),
+            2 => (objects[0].clone(), objects[1].clone()),
+            _ => {
+                objects.sort_by(|a, b| box_compare(a, b, axis));
+                let mid = objects.len() / 2;
+                let right_objects = objects.split_off(mid);
+                (
+                    Arc::new(BVHNode::new(objects)),
+                    Arc::new(BVHNode::new(right_objects)),
+                )
+            }
+        };
+
+        BVHNode { left, right, bbox }
+    }
+}
+
+fn box_compare(a: &Arc<dyn Hittable>, b: &Arc<dyn Hittable>, axis: usize) -> Ordering {
+    let (a_min, _) = a.bounding_box().axis_interval(axis);
+    let (b_min, _) = b.bounding_box().axis_interval(axis);
+    a_min.partial_cmp(&b_min).unwrap_or(Ordering::Equal)
+}
+
+impl Hittable for BVHNode {
+    fn hit(&self, r: &Ray, t_min: f64, t_max: f64, rng: &mut dyn RngCore) -> Option<HitRecord> {
+        if !self.bbox.hit(r, t_min, t_max) {
+            return None;
+        }
+
+        let hit_left = self.left.hit(r, t_min, t_max, rng);
+        let new_t_max = hit_left.as_ref().map_or(t_max, |rec| rec.t);
+        let hit_right = self.right.hit(r, t_min, new_t_max, rng);
+
+        hit_right.or(hit_left)
+    }
+
+    fn bounding_box(&self) -> AABB {
+        self.bbox
+    }
+}
@@ -0,0 +1,212 @@
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use rayon::prelude::*;
+
+use crate::external::write_output;
+use crate::hittable::{Color, Hittable, Point3, Ray, Vec3};
+use crate::utils::{degrees_to_radians, random_double_from, random_double_range_from};
+
+/// What a ray that escapes the scene without hitting anything resolves to.
+/// A black `Solid` background makes emissive materials the only light
+/// source, which is what an enclosed Cornell box wants; `Gradient`
+/// interpolates between its two colors by the ray direction's `y`
+/// component, for outdoor scenes that want a sky.
+#[derive(Clone, Copy)]
+pub enum Background {
+    Solid(Color),
+    Gradient(Color, Color),
+}
+
+impl Background {
+    fn sample(&self, r: &Ray) -> Color {
+        match self {
+            Background::Solid(color) => *color,
+            Background::Gradient(bottom, top) => {
+                let a = 0.5 * (r.dir.unit_vector().y + 1.0);
+                *bottom * (1.0 - a) + *top * a
+            }
+        }
+    }
+}
+
+/// A pinhole (optionally thin-lens) camera: builds the view basis and pixel
+/// grid once in `new`, then `render` walks every pixel, averaging
+/// `samples_per_pixel` jittered rays through a recursive path tracer.
+pub struct Camera {
+    image_width: u32,
+    image_height: u32,
+    samples_per_pixel: u32,
+    max_depth: u32,
+    center: Point3,
+    pixel00_loc: Point3,
+    pixel_delta_u: Vec3,
+    pixel_delta_v: Vec3,
+    defocus_angle: f64,
+    defocus_disk_u: Vec3,
+    defocus_disk_v: Vec3,
+    background: Background,
+    shutter_open: f64,
+    shutter_close: f64,
+}
+
+impl Camera {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        aspect_ratio: f64,
+        image_width: u32,
+        vfov: f64,
+        lookfrom: Point3,
+        lookat: Point3,
+        vup: Vec3,
+        defocus_angle: f64,
+        focus_dist: f64,
+        background: Background,
+        shutter_open: f64,
+        shutter_close: f64,
+    ) -> Self {
+        let image_height = ((image_width as f64 / aspect_ratio) as u32).max(1);
+
+        let theta = degrees_to_radians(vfov);
+        let h = (theta / 2.0).tan();
+        let viewport_height = 2.0 * h * focus_dist;
+        let viewport_width = viewport_height * (image_width as f64 / image_height as f64);
+
+        let w = (lookfrom - lookat).unit_vector();
+        let u = vup.cross(&w).unit_vector();
+        let v = w.cross(&u);
+
+        let viewport_u = u * viewport_width;
+        let viewport_v = -v * viewport_height;
+
+        let pixel_delta_u = viewport_u / image_width as f64;
+        let pixel_delta_v = viewport_v / image_height as f64;
+
+        let viewport_upper_left = lookfrom - (w * focus_dist) - viewport_u / 2.0 - viewport_v / 2.0;
+        let pixel00_loc = viewport_upper_left + (pixel_delta_u + pixel_delta_v) * 0.5;
+
+        let defocus_radius = focus_dist * degrees_to_radians(defocus_angle / 2.0).tan();
+        let defocus_disk_u = u * defocus_radius;
+        let defocus_disk_v = v * defocus_radius;
+
+        Camera {
+            image_width,
+            image_height,
+            samples_per_pixel: 200,
+            max_depth: 50,
+            center: lookfrom,
+            pixel00_loc,
+            pixel_delta_u,
+            pixel_delta_v,
+            defocus_angle,
+            defocus_disk_u,
+            defocus_disk_v,
+            background,
+            shutter_open,
+            shutter_close,
+        }
+    }
+
+    fn get_ray(&self, i: u32, j: u32, rng: &mut dyn RngCore) -> Ray {
+        let offset_u = random_double_from(rng) - 0.5;
+        let offset_v = random_double_from(rng) - 0.5;
+        let pixel_sample = self.pixel00_loc
+            + self.pixel_delta_u * (i as f64 + offset_u)
+            + self.pixel_delta_v * (j as f64 + offset_v);
+
+        let origin = if self.defocus_angle <= 0.0 {
+            self.center
+        } else {
+            self.defocus_disk_sample(rng)
+        };
+        let dir = pixel_sample - origin;
+        let time = random_double_range_from(rng, self.shutter_open, self.shutter_close);
+
+        Ray::new_at_time(origin, dir, time)
+    }
+
+    fn defocus_disk_sample(&self, rng: &mut dyn RngCore) -> Point3 {
+        let p = Vec3::random_in_unit_disk_from(rng);
+        self.center + self.defocus_disk_u * p.x + self.defocus_disk_v * p.y
+    }
+
+    fn ray_color(&self, r: &Ray, world: &dyn Hittable, depth: u32, rng: &mut dyn RngCore) -> Color {
+        if depth == 0 {
+            return Color::zero();
+        }
+
+        let Some(rec) = world.hit(r, 0.001, f64::INFINITY, rng) else {
+            return self.background.sample(r);
+        };
+
+        let emitted = rec.mat.emitted();
+        match rec.mat.scatter(r, &rec, rng) {
+            Some((attenuation, scattered)) => {
+                emitted + attenuation * self.ray_color(&scattered, world, depth - 1, rng)
+            }
+            None => emitted,
+        }
+    }
+
+    /// Gamma-2 corrects a linear pixel and clamps it into an 8-bit RGB
+    /// triple, the single place this happens so every output encoder
+    /// (PPM, PNG, JPEG) sees the same bytes.
+    fn to_rgb8(pixel_color: Color) -> [u8; 3] {
+        let r = pixel_color.x.max(0.0).sqrt();
+        let g = pixel_color.y.max(0.0).sqrt();
+        let b = pixel_color.z.max(0.0).sqrt();
+
+        [
+            (256.0 * r.clamp(0.0, 0.999)) as u8,
+            (256.0 * g.clamp(0.0, 0.999)) as u8,
+            (256.0 * b.clamp(0.0, 0.999)) as u8,
+        ]
+    }
+
+    /// Renders one scanline. Each row runs as its own rayon task and owns a
+    /// `ChaCha8Rng` seeded from its row index, so every sample a pixel takes
+    /// draws from that row's own stream instead of the ambient thread-local
+    /// one: rows never share mutable state (parallel run is data-race free
+    /// regardless of how rayon schedules them across cores) *and* re-running
+    /// the same scene reproduces the same per-pixel noise pattern, since a
+    /// row's samples no longer depend on how many other rays happened to
+    /// draw from the shared generator first.
+    fn render_row(&self, world: &dyn Hittable, j: u32) -> Vec<Color> {
+        let mut rng = ChaCha8Rng::seed_from_u64(j as u64);
+
+        (0..self.image_width)
+            .map(|i| {
+                let mut pixel_color = Color::zero();
+                for _ in 0..self.samples_per_pixel {
+                    let r = self.get_ray(i, j, &mut rng);
+                    pixel_color += self.ray_color(&r, world, self.max_depth, &mut rng);
+                }
+                pixel_color / self.samples_per_pixel as f64
+            })
+            .collect()
+    }
+
+    pub fn render(&self, world: &dyn Hittable, path: &str) {
+        let rows_done = AtomicU32::new(0);
+
+        // `into_par_iter` over an indexed range keeps `collect`'s output in
+        // row order even though rows finish in whatever order rayon picks.
+        let pixels: Vec<Color> = (0..self.image_height)
+            .into_par_iter()
+            .flat_map(|j| {
+                let row = self.render_row(world, j);
+                let done = rows_done.fetch_add(1, Ordering::Relaxed) + 1;
+                eprint!("\rScanlines remaining: {} ", self.image_height - done);
+                io::stderr().flush().ok();
+                row
+            })
+            .collect();
+
+        let buffer: Vec<u8> = pixels.iter().flat_map(|&c| Camera::to_rgb8(c)).collect();
+        write_output(path, self.image_width, self.image_height, &buffer);
+
+        eprintln!("\rDone.                     ");
+    }
+}
@@ -0,0 +1,68 @@
+//! Thin wrappers around third-party crates used to import and export image
+//! data, kept separate from the core math/render pipeline so those modules
+//! never depend directly on the `image` crate.
+
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+
+use image::{ImageBuffer, Rgb};
+
+/// A decoded 8-bit RGB image, row-major, origin at the top-left.
+pub struct RawImage {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<u8>,
+}
+
+impl RawImage {
+    /// Loads any format the `image` crate supports (JPEG, PNG, ...).
+    /// Returns `None` if the file is missing or unreadable so callers can
+    /// fall back to a solid magenta "no texture" debug color.
+    pub fn load(path: &str) -> Option<RawImage> {
+        let img = image::open(path).ok()?.into_rgb8();
+        let (width, height) = img.dimensions();
+        Some(RawImage { width, height, pixels: img.into_raw() })
+    }
+
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 3] {
+        let idx = ((y * self.width + x) * 3) as usize;
+        [self.pixels[idx], self.pixels[idx + 1], self.pixels[idx + 2]]
+    }
+}
+
+/// Writes an 8-bit RGB buffer to `path`, letting the `image` crate pick the
+/// encoder from the file extension.
+pub fn write_image(path: &str, width: u32, height: u32, pixels: &[u8]) -> image::ImageResult<()> {
+    let buffer: ImageBuffer<Rgb<u8>, _> =
+        ImageBuffer::from_raw(width, height, pixels.to_vec()).expect("pixel buffer size mismatch");
+    buffer.save(path)
+}
+
+/// Writes an 8-bit RGB buffer to `path`, picking the encoder from the file
+/// extension: `.png` and `.jpg`/`.jpeg` go through `write_image`, anything
+/// else (including the original `.ppm`) falls back to plain-text PPM so
+/// renders keep working without the `image` crate needing a PNM feature.
+pub fn write_output(path: &str, width: u32, height: u32, pixels: &[u8]) {
+    let ext = Path::new(path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+
+    match ext.as_str() {
+        "png" | "jpg" | "jpeg" => {
+            write_image(path, width, height, pixels).expect("failed to encode image")
+        }
+        _ => write_ppm(path, width, height, pixels).expect("failed to write ppm file"),
+    }
+}
+
+fn write_ppm(path: &str, width: u32, height: u32, pixels: &[u8]) -> io::Result<()> {
+    let mut file = File::create(path)?;
+    writeln!(file, "P3\n{width} {height}\n255")?;
+    for rgb in pixels.chunks_exact(3) {
+        writeln!(file, "{} {} {}", rgb[0], rgb[1], rgb[2])?;
+    }
+    Ok(())
+}
@@ -0,0 +1,322 @@
+use std::ops::{Add, AddAssign, Div, DivAssign, Index, Mul, MulAssign, Neg, Sub};
+use std::sync::Arc;
+
+use rand::RngCore;
+
+use crate::bvh::AABB;
+use crate::materials::Material;
+use crate::utils::{random_double_range, random_double_range_from};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vec3 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+pub type Point3 = Vec3;
+pub type Color = Vec3;
+
+impl Vec3 {
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+        Vec3 { x, y, z }
+    }
+
+    pub const fn zero() -> Self {
+        Vec3::new(0.0, 0.0, 0.0)
+    }
+
+    pub fn length(&self) -> f64 {
+        self.length_squared().sqrt()
+    }
+
+    pub fn length_squared(&self) -> f64 {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
+    pub fn dot(&self, other: &Vec3) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(&self, other: &Vec3) -> Vec3 {
+        Vec3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    pub fn unit_vector(&self) -> Vec3 {
+        *self / self.length()
+    }
+
+    pub fn near_zero(&self) -> bool {
+        let s = 1e-8;
+        self.x.abs() < s && self.y.abs() < s && self.z.abs() < s
+    }
+
+    pub fn random() -> Vec3 {
+        Vec3::new(
+            random_double_range(0.0, 1.0),
+            random_double_range(0.0, 1.0),
+            random_double_range(0.0, 1.0),
+        )
+    }
+
+    pub fn random_range(min: f64, max: f64) -> Vec3 {
+        Vec3::new(
+            random_double_range(min, max),
+            random_double_range(min, max),
+            random_double_range(min, max),
+        )
+    }
+
+    pub fn random_in_unit_sphere() -> Vec3 {
+        loop {
+            let p = Vec3::random_range(-1.0, 1.0);
+            if p.length_squared() < 1.0 {
+                return p;
+            }
+        }
+    }
+
+    pub fn random_unit_vector() -> Vec3 {
+        Vec3::random_in_unit_sphere().unit_vector()
+    }
+
+    pub fn random_in_unit_disk() -> Vec3 {
+        loop {
+            let p = Vec3::new(
+                random_double_range(-1.0, 1.0),
+                random_double_range(-1.0, 1.0),
+                0.0,
+            );
+            if p.length_squared() < 1.0 {
+                return p;
+            }
+        }
+    }
+
+    /// Same as `random_range`, but draws from the caller's `rng` instead of
+    /// the ambient thread-local one; see `random_double_from`.
+    pub fn random_range_from(rng: &mut dyn RngCore, min: f64, max: f64) -> Vec3 {
+        Vec3::new(
+            random_double_range_from(rng, min, max),
+            random_double_range_from(rng, min, max),
+            random_double_range_from(rng, min, max),
+        )
+    }
+
+    pub fn random_in_unit_sphere_from(rng: &mut dyn RngCore) -> Vec3 {
+        loop {
+            let p = Vec3::random_range_from(rng, -1.0, 1.0);
+            if p.length_squared() < 1.0 {
+                return p;
+            }
+        }
+    }
+
+    pub fn random_unit_vector_from(rng: &mut dyn RngCore) -> Vec3 {
+        Vec3::random_in_unit_sphere_from(rng).unit_vector()
+    }
+
+    pub fn random_in_unit_disk_from(rng: &mut dyn RngCore) -> Vec3 {
+        loop {
+            let p = Vec3::new(
+                random_double_range_from(rng, -1.0, 1.0),
+                random_double_range_from(rng, -1.0, 1.0),
+                0.0,
+            );
+            if p.length_squared() < 1.0 {
+                return p;
+            }
+        }
+    }
+
+    pub fn reflect(&self, n: &Vec3) -> Vec3 {
+        *self - *n * 2.0 * self.dot(n)
+    }
+
+    pub fn refract(&self, n: &Vec3, etai_over_etat: f64) -> Vec3 {
+        let cos_theta = (-*self).dot(n).min(1.0);
+        let r_out_perp = (*self + *n * cos_theta) * etai_over_etat;
+        let r_out_parallel = *n * -(1.0 - r_out_perp.length_squared()).abs().sqrt();
+        r_out_perp + r_out_parallel
+    }
+}
+
+impl Index<usize> for Ray {
+    type Output = f64;
+    fn index(&self, axis: usize) -> &f64 {
+        &self.dir[axis]
+    }
+}
+
+impl Index<usize> for Vec3 {
+    type Output = f64;
+    fn index(&self, i: usize) -> &f64 {
+        match i {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Vec3 index out of range: {i}"),
+        }
+    }
+}
+
+impl Add for Vec3 {
+    type Output = Vec3;
+    fn add(self, rhs: Vec3) -> Vec3 {
+        Vec3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl AddAssign for Vec3 {
+    fn add_assign(&mut self, rhs: Vec3) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Vec3 {
+    type Output = Vec3;
+    fn sub(self, rhs: Vec3) -> Vec3 {
+        Vec3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl Neg for Vec3 {
+    type Output = Vec3;
+    fn neg(self) -> Vec3 {
+        Vec3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<f64> for Vec3 {
+    type Output = Vec3;
+    fn mul(self, t: f64) -> Vec3 {
+        Vec3::new(self.x * t, self.y * t, self.z * t)
+    }
+}
+
+impl Mul<Vec3> for Vec3 {
+    type Output = Vec3;
+    fn mul(self, rhs: Vec3) -> Vec3 {
+        Vec3::new(self.x * rhs.x, self.y * rhs.y, self.z * rhs.z)
+    }
+}
+
+impl MulAssign<f64> for Vec3 {
+    fn mul_assign(&mut self, t: f64) {
+        *self = *self * t;
+    }
+}
+
+impl Div<f64> for Vec3 {
+    type Output = Vec3;
+    fn div(self, t: f64) -> Vec3 {
+        self * (1.0 / t)
+    }
+}
+
+impl DivAssign<f64> for Vec3 {
+    fn div_assign(&mut self, t: f64) {
+        *self *= 1.0 / t;
+    }
+}
+
+/// A ray `p(t) = origin + t * dir`, optionally carrying a `time` sample used
+/// by time-varying geometry (e.g. motion blur).
+#[derive(Debug, Clone, Copy)]
+pub struct Ray {
+    pub origin: Point3,
+    pub dir: Vec3,
+    pub time: f64,
+}
+
+impl Ray {
+    pub fn new(origin: Point3, dir: Vec3) -> Self {
+        Ray { origin, dir, time: 0.0 }
+    }
+
+    pub fn new_at_time(origin: Point3, dir: Vec3, time: f64) -> Self {
+        Ray { origin, dir, time }
+    }
+
+    pub fn at(&self, t: f64) -> Point3 {
+        self.origin + self.dir * t
+    }
+}
+
+/// Geometric and material data recorded at the closest ray-object intersection.
+#[derive(Clone)]
+pub struct HitRecord {
+    pub p: Point3,
+    pub normal: Vec3,
+    pub mat: Arc<Material>,
+    pub t: f64,
+    pub u: f64,
+    pub v: f64,
+    pub front_face: bool,
+}
+
+impl HitRecord {
+    /// Sets `normal` and `front_face` so `normal` always points against the
+    /// incident ray; `outward_normal` must be unit length.
+    pub fn set_face_normal(&mut self, r: &Ray, outward_normal: Vec3) {
+        self.front_face = r.dir.dot(&outward_normal) < 0.0;
+        self.normal = if self.front_face { outward_normal } else { -outward_normal };
+    }
+}
+
+/// Anything a ray can intersect: primitives, instances (rotation/translation),
+/// volumes, and aggregates (lists, BVH nodes). `rng` is the caller's per-row
+/// generator, threaded down so any randomness a hit test needs (e.g. where
+/// inside a `ConstantMedium` a ray actually scatters) draws from that row's
+/// own reproducible stream instead of the ambient thread-local one.
+pub trait Hittable: Send + Sync {
+    fn hit(&self, r: &Ray, t_min: f64, t_max: f64, rng: &mut dyn RngCore) -> Option<HitRecord>;
+    fn bounding_box(&self) -> AABB;
+}
+
+/// An unordered collection of `Hittable`s, hit-tested by brute-force linear scan.
+#[derive(Clone, Default)]
+pub struct HittableList {
+    pub objects: Vec<Arc<dyn Hittable>>,
+    bbox: AABB,
+}
+
+impl HittableList {
+    pub fn new() -> Self {
+        HittableList { objects: Vec::new(), bbox: AABB::EMPTY }
+    }
+
+    pub fn add(&mut self, object: Arc<dyn Hittable>) {
+        self.bbox = self.bbox.union(&object.bounding_box());
+        self.objects.push(object);
+    }
+
+    pub fn clear(&mut self) {
+        self.objects.clear();
+        self.bbox = AABB::EMPTY;
+    }
+}
+
+impl Hittable for HittableList {
+    fn hit(&self, r: &Ray, t_min: f64, t_max: f64, rng: &mut dyn RngCore) -> Option<HitRecord> {
+        let mut closest_so_far = t_max;
+        let mut hit_anything = None;
+
+        for object in &self.objects {
+            if let Some(rec) = object.hit(r, t_min, closest_so_far, rng) {
+                closest_so_far = rec.t;
+                hit_anything = Some(rec);
+            }
+        }
+
+        hit_anything
+    }
+
+    fn bounding_box(&self) -> AABB {
+        self.bbox
+    }
+}
@@ -11,16 +11,23 @@ mod materials;
 mod bvh;
 mod textures;
 mod external;
+mod volumes;
 
 use primitives::*;
 use materials::*;
 use utils::random_double;
-use camera::Camera;
+use camera::{Background, Camera};
 use hittable::*;
 use bvh::*;
 use textures::*;
+use volumes::ConstantMedium;
 
 fn main() {
+    // The camera's shutter stays open over this time window; anything
+    // that moves within it (see `MovableSphere` below) comes out blurred.
+    let shutter_open = 0.0;
+    let shutter_close = 1.0;
+
     // WORLD
     let mut world = HittableList::new();
 
@@ -179,6 +186,18 @@ fn main() {
         mirror.clone(),
     )));
 
+    // --- Thin haze filling the bottom 90 units of the box ---
+    let haze_boundary = Arc::new(Quad::new_box(
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(555.0, 90.0, 555.0),
+        matte(lambert_white.clone()),
+    ));
+    world.add(Arc::new(ConstantMedium::new(
+        haze_boundary,
+        0.006,
+        Color::new(0.9, 0.9, 0.95),
+    )));
+
     // --- Field of small random spheres on the floor ---
     // Distribution in a central strip, avoiding collisions with main objects
     let mut rng_spheres = Vec::<Arc<dyn Hittable>>::new();
@@ -225,7 +244,15 @@ fn main() {
                 glass.clone()
             };
 
-            world.add(Arc::new(Sphere::new(center, r, mat)) as Arc<dyn Hittable + Send + Sync>);
+            // A handful of the matte spheres bounce upward over the
+            // shutter interval, giving visible motion-blur streaks.
+            let sphere: Arc<dyn Hittable + Send + Sync> = if choose < 0.6 && random_double() < 0.3 {
+                let center1 = center + Vec3::new(0.0, 4.0 + 6.0 * random_double(), 0.0);
+                Arc::new(MovableSphere::new(center, center1, shutter_open, shutter_close, r, mat))
+            } else {
+                Arc::new(Sphere::new(center, r, mat))
+            };
+            world.add(sphere);
         }
     }
 
@@ -238,6 +265,9 @@ fn main() {
     let vup = Vec3::new(0.0, 1.0, 0.0);
     let defocus_angle = 0.0;
     let focus_dist = (lookfrom - lookat).length();
+    // Rays that leave the box through its open front see this background;
+    // pure black keeps emissive materials as the only light source.
+    let background = Background::Solid(Color::zero());
 
     let cam = Camera::new(
         aspect_ratio,
@@ -248,6 +278,9 @@ fn main() {
         vup,
         defocus_angle,
         focus_dist,
+        background,
+        shutter_open,
+        shutter_close,
     );
 
     // --- BVH and render ---
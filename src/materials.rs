@@ -0,0 +1,207 @@
+use std::sync::Arc;
+
+use rand::RngCore;
+
+use crate::hittable::{Color, HitRecord, Ray, Vec3};
+use crate::textures::{SolidColor, Texture};
+use crate::utils::random_double_from;
+
+/// A single scattering model: given the incoming ray and the hit it produced,
+/// decide whether the ray continues and in what direction/tint. `rng` is the
+/// caller's per-row generator, threaded through so every sample a pixel
+/// takes draws from that row's own reproducible stream rather than the
+/// ambient thread-local one.
+pub trait ScatteringFunction: Send + Sync {
+    fn scatter(&self, r_in: &Ray, rec: &HitRecord, rng: &mut dyn RngCore) -> Option<(Color, Ray)>;
+}
+
+/// Diffuse (matte) reflection: scatters toward a random direction in the
+/// hemisphere around the surface normal, tinted by a texture.
+pub struct Lambertian {
+    texture: Arc<dyn Texture>,
+}
+
+impl Lambertian {
+    pub fn new(albedo: Color) -> Arc<Self> {
+        Arc::new(Lambertian { texture: Arc::new(SolidColor::new(albedo)) })
+    }
+
+    pub fn new_from_texture(texture: Arc<dyn Texture>) -> Arc<Self> {
+        Arc::new(Lambertian { texture })
+    }
+}
+
+impl ScatteringFunction for Lambertian {
+    fn scatter(&self, r_in: &Ray, rec: &HitRecord, rng: &mut dyn RngCore) -> Option<(Color, Ray)> {
+        let mut scatter_direction = rec.normal + Vec3::random_unit_vector_from(rng);
+        if scatter_direction.near_zero() {
+            scatter_direction = rec.normal;
+        }
+
+        let scattered = Ray::new_at_time(rec.p, scatter_direction, r_in.time);
+        let attenuation = self.texture.value(rec.u, rec.v, &rec.p);
+        Some((attenuation, scattered))
+    }
+}
+
+/// Ideal (fuzz-free) mirror reflection.
+pub struct Specular;
+
+impl Specular {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Specular)
+    }
+}
+
+impl ScatteringFunction for Specular {
+    fn scatter(&self, r_in: &Ray, rec: &HitRecord, _rng: &mut dyn RngCore) -> Option<(Color, Ray)> {
+        let reflected = r_in.dir.unit_vector().reflect(&rec.normal);
+        let scattered = Ray::new_at_time(rec.p, reflected, r_in.time);
+        if scattered.dir.dot(&rec.normal) > 0.0 {
+            Some((Color::new(1.0, 1.0, 1.0), scattered))
+        } else {
+            None
+        }
+    }
+}
+
+/// A dielectric (glass-like) surface that refracts or reflects according to
+/// its index of refraction, with Schlick's approximation choosing between
+/// the two at grazing angles.
+pub struct Refractive {
+    ior: f64,
+}
+
+impl Refractive {
+    pub fn new(ior: f64) -> Arc<Self> {
+        Arc::new(Refractive { ior })
+    }
+
+    fn reflectance(cosine: f64, ref_idx: f64) -> f64 {
+        let r0 = ((1.0 - ref_idx) / (1.0 + ref_idx)).powi(2);
+        r0 + (1.0 - r0) * (1.0 - cosine).powi(5)
+    }
+}
+
+impl ScatteringFunction for Refractive {
+    fn scatter(&self, r_in: &Ray, rec: &HitRecord, rng: &mut dyn RngCore) -> Option<(Color, Ray)> {
+        let refraction_ratio = if rec.front_face { 1.0 / self.ior } else { self.ior };
+
+        let unit_direction = r_in.dir.unit_vector();
+        let cos_theta = (-unit_direction).dot(&rec.normal).min(1.0);
+        let sin_theta = (1.0 - cos_theta * cos_theta).sqrt();
+
+        let cannot_refract = refraction_ratio * sin_theta > 1.0;
+        let direction = if cannot_refract
+            || Self::reflectance(cos_theta, refraction_ratio) > random_double_from(rng)
+        {
+            unit_direction.reflect(&rec.normal)
+        } else {
+            unit_direction.refract(&rec.normal, refraction_ratio)
+        };
+
+        let scattered = Ray::new_at_time(rec.p, direction, r_in.time);
+        Some((Color::new(1.0, 1.0, 1.0), scattered))
+    }
+}
+
+/// Isotropic phase function: scatters uniformly in every direction, used for
+/// the inside of participating media (smoke, fog).
+pub struct Isotropic {
+    texture: Arc<dyn Texture>,
+}
+
+impl Isotropic {
+    pub fn new(albedo: Color) -> Arc<Self> {
+        Arc::new(Isotropic { texture: Arc::new(SolidColor::new(albedo)) })
+    }
+
+    pub fn new_from_texture(texture: Arc<dyn Texture>) -> Arc<Self> {
+        Arc::new(Isotropic { texture })
+    }
+}
+
+impl ScatteringFunction for Isotropic {
+    fn scatter(&self, r_in: &Ray, rec: &HitRecord, rng: &mut dyn RngCore) -> Option<(Color, Ray)> {
+        let scattered = Ray::new_at_time(rec.p, Vec3::random_unit_vector_from(rng), r_in.time);
+        let attenuation = self.texture.value(rec.u, rec.v, &rec.p);
+        Some((attenuation, scattered))
+    }
+}
+
+/// A surface material as a weighted mixture of up to three scattering
+/// functions (diffuse/specular/refractive) plus an independent emission
+/// term, letting scenes blend e.g. "mostly matte with a hint of gloss" or
+/// "diffuse wall that also glows" without a new material type per
+/// combination.
+pub struct Material {
+    diffuse: Arc<dyn ScatteringFunction>,
+    specular: Arc<dyn ScatteringFunction>,
+    refractive: Arc<dyn ScatteringFunction>,
+    emission: Option<Color>,
+    k_diffuse: f64,
+    k_specular: f64,
+    k_refractive: f64,
+    k_emission: f64,
+}
+
+impl Material {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        diffuse: Arc<dyn ScatteringFunction>,
+        specular: Arc<dyn ScatteringFunction>,
+        refractive: Arc<dyn ScatteringFunction>,
+        emission: Option<Color>,
+        k_diffuse: f64,
+        k_specular: f64,
+        k_refractive: f64,
+        k_emission: f64,
+    ) -> Self {
+        Material {
+            diffuse,
+            specular,
+            refractive,
+            emission,
+            k_diffuse,
+            k_specular,
+            k_refractive,
+            k_emission,
+        }
+    }
+
+    /// Convenience constructor for a pure isotropic phase function, used by
+    /// `ConstantMedium` so volumes don't need to hand-roll the mixture
+    /// weights every time.
+    pub fn isotropic(texture: Arc<dyn Texture>) -> Arc<Self> {
+        Arc::new(Material::new(
+            Isotropic::new_from_texture(texture),
+            Specular::new(),
+            Refractive::new(1.0),
+            None,
+            1.0, 0.0, 0.0, 0.0,
+        ))
+    }
+
+    pub fn scatter(&self, r_in: &Ray, rec: &HitRecord, rng: &mut dyn RngCore) -> Option<(Color, Ray)> {
+        let total = self.k_diffuse + self.k_specular + self.k_refractive;
+        if total <= 0.0 {
+            return None;
+        }
+
+        let choice = random_double_from(rng) * total;
+        if choice < self.k_diffuse {
+            self.diffuse.scatter(r_in, rec, rng)
+        } else if choice < self.k_diffuse + self.k_specular {
+            self.specular.scatter(r_in, rec, rng)
+        } else {
+            self.refractive.scatter(r_in, rec, rng)
+        }
+    }
+
+    pub fn emitted(&self) -> Color {
+        match self.emission {
+            Some(e) => e * self.k_emission,
+            None => Color::zero(),
+        }
+    }
+}
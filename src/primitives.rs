@@ -0,0 +1,619 @@
+use std::fs;
+use std::sync::Arc;
+
+use rand::RngCore;
+
+use crate::bvh::AABB;
+use crate::hittable::{HitRecord, Hittable, HittableList, Point3, Ray, Vec3};
+use crate::materials::Material;
+use crate::utils::degrees_to_radians;
+
+pub struct Sphere {
+    center: Point3,
+    radius: f64,
+    mat: Arc<Material>,
+}
+
+impl Sphere {
+    pub fn new(center: Point3, radius: f64, mat: Arc<Material>) -> Self {
+        Sphere { center, radius: radius.max(0.0), mat }
+    }
+
+    /// `(u, v)` for a point on the unit sphere, using a standard
+    /// equirectangular (latitude/longitude) mapping.
+    fn uv(p: &Point3) -> (f64, f64) {
+        let theta = (-p.y).acos();
+        let phi = (-p.z).atan2(p.x) + std::f64::consts::PI;
+        (phi / (2.0 * std::f64::consts::PI), theta / std::f64::consts::PI)
+    }
+}
+
+impl Hittable for Sphere {
+    fn hit(&self, r: &Ray, t_min: f64, t_max: f64, _rng: &mut dyn RngCore) -> Option<HitRecord> {
+        let oc = self.center - r.origin;
+        let a = r.dir.length_squared();
+        let h = r.dir.dot(&oc);
+        let c = oc.length_squared() - self.radius * self.radius;
+
+        let discriminant = h * h - a * c;
+        if discriminant < 0.0 {
+            return None;
+        }
+        let sqrtd = discriminant.sqrt();
+
+        let mut root = (h - sqrtd) / a;
+        if root <= t_min || t_max <= root {
+            root = (h + sqrtd) / a;
+            if root <= t_min || t_max <= root {
+                return None;
+            }
+        }
+
+        let p = r.at(root);
+        let outward_normal = (p - self.center) / self.radius;
+        let (u, v) = Sphere::uv(&outward_normal);
+
+        let mut rec = HitRecord {
+            p,
+            normal: outward_normal,
+            mat: self.mat.clone(),
+            t: root,
+            u,
+            v,
+            front_face: true,
+        };
+        rec.set_face_normal(r, outward_normal);
+        Some(rec)
+    }
+
+    fn bounding_box(&self) -> AABB {
+        let radius_vec = Vec3::new(self.radius, self.radius, self.radius);
+        AABB::new(self.center - radius_vec, self.center + radius_vec)
+    }
+}
+
+/// A sphere whose center linearly interpolates between `center0` at
+/// `time0` and `center1` at `time1`, giving motion-blurred streaks when
+/// sampled at the jittered shutter times `Camera` assigns to each ray.
+pub struct MovableSphere {
+    center0: Point3,
+    center1: Point3,
+    time0: f64,
+    time1: f64,
+    radius: f64,
+    mat: Arc<Material>,
+    bbox: AABB,
+}
+
+impl MovableSphere {
+    pub fn new(
+        center0: Point3,
+        center1: Point3,
+        time0: f64,
+        time1: f64,
+        radius: f64,
+        mat: Arc<Material>,
+    ) -> Self {
+        let radius = radius.max(0.0);
+        let radius_vec = Vec3::new(radius, radius, radius);
+        let bbox0 = AABB::new(center0 - radius_vec, center0 + radius_vec);
+        let bbox1 = AABB::new(center1 - radius_vec, center1 + radius_vec);
+
+        MovableSphere {
+            center0,
+            center1,
+            time0,
+            time1,
+            radius,
+            mat,
+            bbox: bbox0.union(&bbox1),
+        }
+    }
+
+    /// The sphere's center at ray time `time`, linearly interpolated
+    /// between the two endpoints.
+    fn center(&self, time: f64) -> Point3 {
+        let t = (time - self.time0) / (self.time1 - self.time0);
+        self.center0 + (self.center1 - self.center0) * t
+    }
+}
+
+impl Hittable for MovableSphere {
+    fn hit(&self, r: &Ray, t_min: f64, t_max: f64, _rng: &mut dyn RngCore) -> Option<HitRecord> {
+        let center = self.center(r.time);
+
+        let oc = center - r.origin;
+        let a = r.dir.length_squared();
+        let h = r.dir.dot(&oc);
+        let c = oc.length_squared() - self.radius * self.radius;
+
+        let discriminant = h * h - a * c;
+        if discriminant < 0.0 {
+            return None;
+        }
+        let sqrtd = discriminant.sqrt();
+
+        let mut root = (h - sqrtd) / a;
+        if root <= t_min || t_max <= root {
+            root = (h + sqrtd) / a;
+            if root <= t_min || t_max <= root {
+                return None;
+            }
+        }
+
+        let p = r.at(root);
+        let outward_normal = (p - center) / self.radius;
+        let (u, v) = Sphere::uv(&outward_normal);
+
+        let mut rec = HitRecord {
+            p,
+            normal: outward_normal,
+            mat: self.mat.clone(),
+            t: root,
+            u,
+            v,
+            front_face: true,
+        };
+        rec.set_face_normal(r, outward_normal);
+        Some(rec)
+    }
+
+    fn bounding_box(&self) -> AABB {
+        self.bbox
+    }
+}
+
+/// A flat, finite parallelogram spanned by edge vectors `u` and `v` from a
+/// corner `q`, with the interior test done in the quad's own 2D `(alpha, beta)`
+/// coordinates after intersecting the supporting plane.
+pub struct Quad {
+    q: Point3,
+    u: Vec3,
+    v: Vec3,
+    w: Vec3, // constant used to project a plane-hit point into (alpha, beta)
+    normal: Vec3,
+    d: f64,
+    mat: Arc<Material>,
+    bbox: AABB,
+}
+
+impl Quad {
+    pub fn new(q: Point3, u: Vec3, v: Vec3, mat: Arc<Material>) -> Self {
+        let n = u.cross(&v);
+        let normal = n.unit_vector();
+        let d = normal.dot(&q);
+        let w = n / n.dot(&n);
+
+        let bbox_diagonal1 = AABB::new(q, q + u + v);
+        let bbox_diagonal2 = AABB::new(q + u, q + v);
+        let bbox = bbox_diagonal1.union(&bbox_diagonal2).pad();
+
+        Quad { q, u, v, w, normal, d, mat, bbox }
+    }
+
+    /// Builds a closed, axis-aligned box out of six quads spanning the
+    /// opposite corners `a` and `b`.
+    pub fn new_box(a: Point3, b: Point3, mat: Arc<Material>) -> HittableList {
+        let mut sides = HittableList::new();
+
+        let min = Point3::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z));
+        let max = Point3::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z));
+
+        let dx = Vec3::new(max.x - min.x, 0.0, 0.0);
+        let dy = Vec3::new(0.0, max.y - min.y, 0.0);
+        let dz = Vec3::new(0.0, 0.0, max.z - min.z);
+
+        sides.add(Arc::new(Quad::new(Point3::new(min.x, min.y, max.z), dx, dy, mat.clone()))); // front
+        sides.add(Arc::new(Quad::new(Point3::new(max.x, min.y, max.z), -dz, dy, mat.clone()))); // right
+        sides.add(Arc::new(Quad::new(Point3::new(max.x, min.y, min.z), -dx, dy, mat.clone()))); // back
+        sides.add(Arc::new(Quad::new(Point3::new(min.x, min.y, min.z), dz, dy, mat.clone()))); // left
+        sides.add(Arc::new(Quad::new(Point3::new(min.x, max.y, max.z), dx, -dz, mat.clone()))); // top
+        sides.add(Arc::new(Quad::new(Point3::new(min.x, min.y, min.z), dx, dz, mat))); // bottom
+
+        sides
+    }
+}
+
+impl Hittable for Quad {
+    fn hit(&self, r: &Ray, t_min: f64, t_max: f64, _rng: &mut dyn RngCore) -> Option<HitRecord> {
+        let denom = self.normal.dot(&r.dir);
+        if denom.abs() < 1e-8 {
+            return None;
+        }
+
+        let t = (self.d - self.normal.dot(&r.origin)) / denom;
+        if t <= t_min || t_max <= t {
+            return None;
+        }
+
+        let intersection = r.at(t);
+        let planar_hitpt = intersection - self.q;
+        let alpha = self.w.dot(&planar_hitpt.cross(&self.v));
+        let beta = self.w.dot(&self.u.cross(&planar_hitpt));
+
+        if !(0.0..=1.0).contains(&alpha) || !(0.0..=1.0).contains(&beta) {
+            return None;
+        }
+
+        let mut rec = HitRecord {
+            p: intersection,
+            normal: self.normal,
+            mat: self.mat.clone(),
+            t,
+            u: alpha,
+            v: beta,
+            front_face: true,
+        };
+        rec.set_face_normal(r, self.normal);
+        Some(rec)
+    }
+
+    fn bounding_box(&self) -> AABB {
+        self.bbox
+    }
+}
+
+/// A single triangle given by three vertices, hit-tested with the
+/// Moller-Trumbore algorithm. `(u, v)` are the barycentric coordinates of
+/// the second and third vertex, so image textures map onto meshes the same
+/// way they do onto quads.
+pub struct Triangle {
+    v0: Point3,
+    v1: Point3,
+    v2: Point3,
+    mat: Arc<Material>,
+    bbox: AABB,
+}
+
+impl Triangle {
+    pub fn new(v0: Point3, v1: Point3, v2: Point3, mat: Arc<Material>) -> Self {
+        let min = Point3::new(
+            v0.x.min(v1.x).min(v2.x),
+            v0.y.min(v1.y).min(v2.y),
+            v0.z.min(v1.z).min(v2.z),
+        );
+        let max = Point3::new(
+            v0.x.max(v1.x).max(v2.x),
+            v0.y.max(v1.y).max(v2.y),
+            v0.z.max(v1.z).max(v2.z),
+        );
+        let bbox = AABB::new(min, max).pad();
+
+        Triangle { v0, v1, v2, mat, bbox }
+    }
+}
+
+impl Hittable for Triangle {
+    fn hit(&self, r: &Ray, t_min: f64, t_max: f64, _rng: &mut dyn RngCore) -> Option<HitRecord> {
+        const EPSILON: f64 = 1e-8;
+
+        let edge1 = self.v1 - self.v0;
+        let edge2 = self.v2 - self.v0;
+        let pvec = r.dir.cross(&edge2);
+        let det = edge1.dot(&pvec);
+
+        if det.abs() < EPSILON {
+            return None; // ray is parallel to the triangle
+        }
+        let inv_det = 1.0 / det;
+
+        let tvec = r.origin - self.v0;
+        let u = tvec.dot(&pvec) * inv_det;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+
+        let qvec = tvec.cross(&edge1);
+        let v = r.dir.dot(&qvec) * inv_det;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+
+        let t = edge2.dot(&qvec) * inv_det;
+        if t <= t_min || t_max <= t {
+            return None;
+        }
+
+        let p = r.at(t);
+        let outward_normal = edge1.cross(&edge2).unit_vector();
+
+        let mut rec = HitRecord {
+            p,
+            normal: outward_normal,
+            mat: self.mat.clone(),
+            t,
+            u,
+            v,
+            front_face: true,
+        };
+        rec.set_face_normal(r, outward_normal);
+        Some(rec)
+    }
+
+    fn bounding_box(&self) -> AABB {
+        self.bbox
+    }
+}
+
+/// Loads a Wavefront `.obj` mesh, triangulating every face with a simple
+/// fan from its first vertex and assigning `mat` to every triangle. Only
+/// `v` (vertex) and `f` (face) lines are understood; normals, texture
+/// coordinates and materials embedded in the file are ignored in favor of
+/// the `mat` passed in. The result is a plain `HittableList`, ready to be
+/// handed to `BVHNode::new` alongside the rest of the scene.
+pub fn load_obj(path: &str, mat: Arc<Material>) -> HittableList {
+    let contents = fs::read_to_string(path).expect("failed to read obj file");
+
+    let mut vertices: Vec<Point3> = Vec::new();
+    let mut mesh = HittableList::new();
+
+    for line in contents.lines() {
+        let mut tokens = line.split_whitespace();
+        match tokens.next() {
+            Some("v") => {
+                let coords: Vec<f64> = tokens
+                    .take(3)
+                    .map(|t| t.parse().expect("invalid obj vertex coordinate"))
+                    .collect();
+                vertices.push(Point3::new(coords[0], coords[1], coords[2]));
+            }
+            Some("f") => {
+                // A face vertex is "i", "i/j", "i/j/k" or "i//k"; the
+                // position index always comes first, and per the OBJ spec is
+                // either a positive 1-based absolute index, or a negative
+                // index relative to the vertex count seen so far in the file.
+                let indices: Vec<usize> = tokens
+                    .map(|t| {
+                        let raw: i64 = t
+                            .split('/')
+                            .next()
+                            .unwrap()
+                            .parse()
+                            .expect("invalid obj face index");
+                        match raw.cmp(&0) {
+                            std::cmp::Ordering::Greater => (raw - 1) as usize,
+                            std::cmp::Ordering::Less => (vertices.len() as i64 + raw) as usize,
+                            std::cmp::Ordering::Equal => panic!("obj face index 0 is not valid"),
+                        }
+                    })
+                    .collect();
+
+                // A face needs at least 3 vertices to triangulate; skip
+                // malformed/empty "f" lines instead of underflowing below.
+                if indices.len() < 3 {
+                    continue;
+                }
+
+                // Fan triangulation: (0, i, i+1) for i in 1..n-1.
+                for i in 1..indices.len() - 1 {
+                    mesh.add(Arc::new(Triangle::new(
+                        vertices[indices[0]],
+                        vertices[indices[i]],
+                        vertices[indices[i + 1]],
+                        mat.clone(),
+                    )));
+                }
+            }
+            _ => {} // comments, normals, texcoords, groups, ... are ignored
+        }
+    }
+
+    mesh
+}
+
+/// Rotates a wrapped hittable around the Y axis by transforming incoming
+/// rays into the wrapped object's local space, then transforming the hit
+/// back into world space.
+pub struct RotationY {
+    object: Arc<dyn Hittable>,
+    sin_theta: f64,
+    cos_theta: f64,
+    bbox: AABB,
+}
+
+impl RotationY {
+    pub fn new(object: Arc<dyn Hittable>, angle_degrees: f64) -> Self {
+        let radians = degrees_to_radians(angle_degrees);
+        let sin_theta = radians.sin();
+        let cos_theta = radians.cos();
+        let bbox = object.bounding_box();
+
+        let mut min = Point3::new(f64::INFINITY, f64::INFINITY, f64::INFINITY);
+        let mut max = Point3::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY);
+
+        for i in 0..2 {
+            for j in 0..2 {
+                for k in 0..2 {
+                    let x = if i == 0 { bbox.min.x } else { bbox.max.x };
+                    let y = if j == 0 { bbox.min.y } else { bbox.max.y };
+                    let z = if k == 0 { bbox.min.z } else { bbox.max.z };
+
+                    let new_x = cos_theta * x + sin_theta * z;
+                    let new_z = -sin_theta * x + cos_theta * z;
+                    let tester = Vec3::new(new_x, y, new_z);
+
+                    min = Point3::new(min.x.min(tester.x), min.y.min(tester.y), min.z.min(tester.z));
+                    max = Point3::new(max.x.max(tester.x), max.y.max(tester.y), max.z.max(tester.z));
+                }
+            }
+        }
+
+        RotationY { object, sin_theta, cos_theta, bbox: AABB::new(min, max) }
+    }
+}
+
+impl Hittable for RotationY {
+    fn hit(&self, r: &Ray, t_min: f64, t_max: f64, rng: &mut dyn RngCore) -> Option<HitRecord> {
+        // World space -> object space.
+        let origin = Point3::new(
+            self.cos_theta * r.origin.x - self.sin_theta * r.origin.z,
+            r.origin.y,
+            self.sin_theta * r.origin.x + self.cos_theta * r.origin.z,
+        );
+        let dir = Vec3::new(
+            self.cos_theta * r.dir.x - self.sin_theta * r.dir.z,
+            r.dir.y,
+            self.sin_theta * r.dir.x + self.cos_theta * r.dir.z,
+        );
+        let rotated_r = Ray::new_at_time(origin, dir, r.time);
+
+        let mut rec = self.object.hit(&rotated_r, t_min, t_max, rng)?;
+
+        // Object space -> world space.
+        rec.p = Point3::new(
+            self.cos_theta * rec.p.x + self.sin_theta * rec.p.z,
+            rec.p.y,
+            -self.sin_theta * rec.p.x + self.cos_theta * rec.p.z,
+        );
+        let world_normal = Vec3::new(
+            self.cos_theta * rec.normal.x + self.sin_theta * rec.normal.z,
+            rec.normal.y,
+            -self.sin_theta * rec.normal.x + self.cos_theta * rec.normal.z,
+        );
+        // `rec.normal` already faces against the object-space ray and the
+        // rotation preserves that, so `front_face` from the object hit stands.
+        rec.normal = world_normal;
+
+        Some(rec)
+    }
+
+    fn bounding_box(&self) -> AABB {
+        self.bbox
+    }
+}
+
+/// Translates a wrapped hittable by a fixed offset.
+pub struct Translation {
+    object: Arc<dyn Hittable>,
+    offset: Vec3,
+    bbox: AABB,
+}
+
+impl Translation {
+    pub fn new(object: Arc<dyn Hittable>, offset: Vec3) -> Self {
+        let bbox = object.bounding_box();
+        let bbox = AABB::new(bbox.min + offset, bbox.max + offset);
+        Translation { object, offset, bbox }
+    }
+}
+
+impl Hittable for Translation {
+    fn hit(&self, r: &Ray, t_min: f64, t_max: f64, rng: &mut dyn RngCore) -> Option<HitRecord> {
+        let offset_r = Ray::new_at_time(r.origin - self.offset, r.dir, r.time);
+
+        let mut rec = self.object.hit(&offset_r, t_min, t_max, rng)?;
+        rec.p += self.offset;
+        Some(rec)
+    }
+
+    fn bounding_box(&self) -> AABB {
+        self.bbox
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::materials::{Material, Refractive, Specular};
+
+    fn test_mat() -> Arc<Material> {
+        Arc::new(Material::new(
+            crate::materials::Lambertian::new(Vec3::zero()),
+            Specular::new(),
+            Refractive::new(1.5),
+            None,
+            1.0, 0.0, 0.0, 0.0,
+        ))
+    }
+
+    #[test]
+    fn triangle_hits_through_its_interior_and_misses_outside_it() {
+        let tri = Triangle::new(
+            Point3::new(-1.0, -1.0, 0.0),
+            Point3::new(1.0, -1.0, 0.0),
+            Point3::new(0.0, 1.0, 0.0),
+            test_mat(),
+        );
+
+        let mut rng = rand::thread_rng();
+
+        let through_center = Ray::new(Point3::new(0.0, 0.0, -5.0), Vec3::new(0.0, 0.0, 1.0));
+        let rec = tri.hit(&through_center, 0.001, f64::INFINITY, &mut rng).expect("should hit");
+        assert!((rec.t - 5.0).abs() < 1e-9);
+
+        let past_the_corner = Ray::new(Point3::new(5.0, 5.0, -5.0), Vec3::new(0.0, 0.0, 1.0));
+        assert!(tri.hit(&past_the_corner, 0.001, f64::INFINITY, &mut rng).is_none());
+    }
+
+    #[test]
+    fn movable_sphere_hit_point_tracks_center_over_time() {
+        let center0 = Point3::new(0.0, 0.0, 0.0);
+        let center1 = Point3::new(10.0, 0.0, 0.0);
+        let sphere = MovableSphere::new(center0, center1, 0.0, 1.0, 1.0, test_mat());
+        let mut rng = rand::thread_rng();
+
+        let ray_at_t0 = Ray::new_at_time(Point3::new(0.0, 0.0, -5.0), Vec3::new(0.0, 0.0, 1.0), 0.0);
+        let rec0 = sphere.hit(&ray_at_t0, 0.001, f64::INFINITY, &mut rng).expect("should hit at time0");
+        assert!((rec0.p - Point3::new(0.0, 0.0, -1.0)).length() < 1e-9);
+        assert!((rec0.normal - Vec3::new(0.0, 0.0, -1.0)).length() < 1e-9);
+
+        let ray_at_t1 = Ray::new_at_time(Point3::new(10.0, 0.0, -5.0), Vec3::new(0.0, 0.0, 1.0), 1.0);
+        let rec1 = sphere.hit(&ray_at_t1, 0.001, f64::INFINITY, &mut rng).expect("should hit at time1");
+        assert!((rec1.p - Point3::new(10.0, 0.0, -1.0)).length() < 1e-9);
+
+        // The sphere moved between the two times, so the same ray shape hits
+        // a different point (and the time0 ray now misses the moved sphere).
+        assert!((rec0.p - rec1.p).length() > 5.0);
+        assert!(sphere.hit(&ray_at_t1, 0.001, f64::INFINITY, &mut rng).is_some());
+        let ray_at_t1_through_old_center =
+            Ray::new_at_time(Point3::new(0.0, 0.0, -5.0), Vec3::new(0.0, 0.0, 1.0), 1.0);
+        assert!(sphere.hit(&ray_at_t1_through_old_center, 0.001, f64::INFINITY, &mut rng).is_none());
+    }
+
+    #[test]
+    fn movable_sphere_bounding_box_is_the_union_of_both_endpoints() {
+        let center0 = Point3::new(0.0, 0.0, 0.0);
+        let center1 = Point3::new(10.0, 0.0, 0.0);
+        let sphere = MovableSphere::new(center0, center1, 0.0, 1.0, 1.0, test_mat());
+
+        let radius_vec = Vec3::new(1.0, 1.0, 1.0);
+        let expected = AABB::new(center0 - radius_vec, center0 + radius_vec)
+            .union(&AABB::new(center1 - radius_vec, center1 + radius_vec));
+
+        let bbox = sphere.bounding_box();
+        assert_eq!(bbox.min, expected.min);
+        assert_eq!(bbox.max, expected.max);
+    }
+
+    #[test]
+    fn load_obj_triangulates_a_fan_and_accepts_relative_indices() {
+        let path = std::env::temp_dir()
+            .join(format!("rusty_renderer_test_{}.obj", std::process::id()));
+        fs::write(&path, "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nf -4 -3 -2 -1\n")
+            .expect("failed to write test obj file");
+
+        let mesh = load_obj(path.to_str().unwrap(), test_mat());
+        fs::remove_file(&path).ok();
+
+        // A planar quad fans into 2 triangles.
+        assert_eq!(mesh.objects.len(), 2);
+
+        let through_the_quad = Ray::new(Point3::new(0.5, 0.5, -5.0), Vec3::new(0.0, 0.0, 1.0));
+        assert!(mesh.hit(&through_the_quad, 0.001, f64::INFINITY, &mut rand::thread_rng()).is_some());
+    }
+
+    #[test]
+    fn load_obj_skips_degenerate_face_lines_instead_of_panicking() {
+        let path = std::env::temp_dir()
+            .join(format!("rusty_renderer_test_degenerate_{}.obj", std::process::id()));
+        fs::write(&path, "v 0 0 0\nv 1 0 0\nv 1 1 0\nf\nf 1 2\n")
+            .expect("failed to write test obj file");
+
+        let mesh = load_obj(path.to_str().unwrap(), test_mat());
+        fs::remove_file(&path).ok();
+
+        // Neither the empty nor the 2-vertex face has enough vertices to
+        // triangulate, so both are skipped rather than underflowing/panicking.
+        assert_eq!(mesh.objects.len(), 0);
+    }
+}
@@ -0,0 +1,251 @@
+use std::sync::Arc;
+
+use rand::seq::SliceRandom;
+
+use crate::external::RawImage;
+use crate::hittable::{Color, Point3, Vec3};
+
+/// Maps a surface point (and its `(u, v)` parameterization) to a color.
+pub trait Texture: Send + Sync {
+    fn value(&self, u: f64, v: f64, p: &Point3) -> Color;
+}
+
+/// A uniform color, used as the default texture backing `Lambertian::new`.
+pub struct SolidColor {
+    albedo: Color,
+}
+
+impl SolidColor {
+    pub fn new(albedo: Color) -> Self {
+        SolidColor { albedo }
+    }
+}
+
+impl Texture for SolidColor {
+    fn value(&self, _u: f64, _v: f64, _p: &Point3) -> Color {
+        self.albedo
+    }
+}
+
+/// Samples an on-disk image, mapping `(u, v) in [0,1]^2` to pixel coordinates.
+pub struct ImageTexture {
+    image: Option<RawImage>,
+}
+
+impl ImageTexture {
+    pub fn new(path: &str) -> Self {
+        ImageTexture { image: RawImage::load(path) }
+    }
+}
+
+impl Texture for ImageTexture {
+    fn value(&self, u: f64, v: f64, _p: &Point3) -> Color {
+        let Some(image) = &self.image else {
+            // Debug magenta so a missing/unreadable texture is obvious in renders.
+            return Color::new(1.0, 0.0, 1.0);
+        };
+
+        let u = u.clamp(0.0, 1.0);
+        let v = 1.0 - v.clamp(0.0, 1.0); // flip so v=0 is the image's bottom row
+
+        let x = (u * image.width as f64) as u32;
+        let y = (v * image.height as f64) as u32;
+        let x = x.min(image.width - 1);
+        let y = y.min(image.height - 1);
+
+        let [r, g, b] = image.pixel(x, y);
+        let scale = 1.0 / 255.0;
+        Color::new(r as f64 * scale, g as f64 * scale, b as f64 * scale)
+    }
+}
+
+/// Alternates between two sub-textures based on the sign of
+/// `sin(k*x)*sin(k*y)*sin(k*z)`, giving a 3D checker pattern that stays
+/// aligned to world space regardless of how the surface is parameterized.
+pub struct CheckerTexture {
+    inv_scale: f64,
+    even: Arc<dyn Texture>,
+    odd: Arc<dyn Texture>,
+}
+
+impl CheckerTexture {
+    pub fn new(scale: f64, even: Arc<dyn Texture>, odd: Arc<dyn Texture>) -> Self {
+        CheckerTexture { inv_scale: 1.0 / scale, even, odd }
+    }
+
+    pub fn new_from_colors(scale: f64, even: Color, odd: Color) -> Self {
+        CheckerTexture::new(scale, Arc::new(SolidColor::new(even)), Arc::new(SolidColor::new(odd)))
+    }
+}
+
+impl Texture for CheckerTexture {
+    fn value(&self, u: f64, v: f64, p: &Point3) -> Color {
+        let sines = (self.inv_scale * p.x).sin() * (self.inv_scale * p.y).sin() * (self.inv_scale * p.z).sin();
+        if sines < 0.0 {
+            self.odd.value(u, v, p)
+        } else {
+            self.even.value(u, v, p)
+        }
+    }
+}
+
+/// A table of random unit vectors plus three independently shuffled
+/// permutations, used to evaluate gradient (Perlin) noise at arbitrary
+/// points without any visible grid artifacts.
+struct Perlin {
+    ranvec: [Vec3; Perlin::POINT_COUNT],
+    perm_x: [usize; Perlin::POINT_COUNT],
+    perm_y: [usize; Perlin::POINT_COUNT],
+    perm_z: [usize; Perlin::POINT_COUNT],
+}
+
+impl Perlin {
+    const POINT_COUNT: usize = 256;
+
+    fn new() -> Self {
+        let mut ranvec = [Vec3::zero(); Perlin::POINT_COUNT];
+        for v in ranvec.iter_mut() {
+            *v = Vec3::random_range(-1.0, 1.0).unit_vector();
+        }
+
+        Perlin {
+            ranvec,
+            perm_x: Perlin::generate_perm(),
+            perm_y: Perlin::generate_perm(),
+            perm_z: Perlin::generate_perm(),
+        }
+    }
+
+    fn generate_perm() -> [usize; Perlin::POINT_COUNT] {
+        let mut perm: [usize; Perlin::POINT_COUNT] = std::array::from_fn(|i| i);
+        perm.shuffle(&mut rand::thread_rng());
+        perm
+    }
+
+    /// Trilinearly interpolates the dot products between each of the 8
+    /// lattice-cell corner gradients and the offset vector from that
+    /// corner, after Hermite-smoothing the fractional offsets so the
+    /// result has continuous derivatives across cell boundaries.
+    fn noise(&self, p: &Point3) -> f64 {
+        let u = p.x - p.x.floor();
+        let v = p.y - p.y.floor();
+        let w = p.z - p.z.floor();
+        let hu = u * u * (3.0 - 2.0 * u);
+        let hv = v * v * (3.0 - 2.0 * v);
+        let hw = w * w * (3.0 - 2.0 * w);
+
+        let i = p.x.floor() as i32;
+        let j = p.y.floor() as i32;
+        let k = p.z.floor() as i32;
+
+        let mut accum = 0.0;
+        for di in 0..2i32 {
+            for dj in 0..2i32 {
+                for dk in 0..2i32 {
+                    let gradient = self.ranvec[self.perm_x[((i + di) & 255) as usize]
+                        ^ self.perm_y[((j + dj) & 255) as usize]
+                        ^ self.perm_z[((k + dk) & 255) as usize]];
+
+                    let weight = Vec3::new(u - di as f64, v - dj as f64, w - dk as f64);
+
+                    let wi = if di == 0 { 1.0 - hu } else { hu };
+                    let wj = if dj == 0 { 1.0 - hv } else { hv };
+                    let wk = if dk == 0 { 1.0 - hw } else { hw };
+
+                    accum += wi * wj * wk * gradient.dot(&weight);
+                }
+            }
+        }
+
+        accum
+    }
+
+    /// Sums `|noise|` over `depth` octaves, doubling the frequency and
+    /// halving the weight each octave, giving the turbulent, marble-vein
+    /// look used by `NoiseTexture`.
+    fn turbulence(&self, p: &Point3, depth: u32) -> f64 {
+        let mut accum = 0.0;
+        let mut temp_p = *p;
+        let mut weight = 1.0;
+
+        for _ in 0..depth {
+            accum += weight * self.noise(&temp_p).abs();
+            weight *= 0.5;
+            temp_p *= 2.0;
+        }
+
+        accum
+    }
+}
+
+/// Marble-like procedural texture: a sine wave along `z`, perturbed by
+/// Perlin turbulence so the bands warp instead of running perfectly
+/// straight.
+pub struct NoiseTexture {
+    noise: Perlin,
+    scale: f64,
+}
+
+impl NoiseTexture {
+    pub fn new(scale: f64) -> Self {
+        NoiseTexture { noise: Perlin::new(), scale }
+    }
+}
+
+impl Texture for NoiseTexture {
+    fn value(&self, _u: f64, _v: f64, p: &Point3) -> Color {
+        let marble = 0.5 * (1.0 + (self.scale * p.z + 10.0 * self.noise.turbulence(p, 7)).sin());
+        Color::new(1.0, 1.0, 1.0) * marble
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checker_alternates_across_a_cell_boundary() {
+        let even = Color::new(1.0, 1.0, 1.0);
+        let odd = Color::new(0.0, 0.0, 0.0);
+        let checker = CheckerTexture::new_from_colors(1.0, even, odd);
+
+        // `sin(x)*sin(y)*sin(z)` is positive just above the origin on every
+        // axis, and flips sign when exactly one axis crosses zero.
+        let inside_even_cell = checker.value(0.0, 0.0, &Point3::new(0.1, 0.1, 0.1));
+        let inside_odd_cell = checker.value(0.0, 0.0, &Point3::new(-0.1, 0.1, 0.1));
+
+        assert_eq!(inside_even_cell, even);
+        assert_eq!(inside_odd_cell, odd);
+    }
+
+    #[test]
+    fn perlin_noise_stays_within_the_expected_range() {
+        let perlin = Perlin::new();
+
+        for p in [
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.7, -3.2, 5.1),
+            Point3::new(-40.0, 12.5, 0.3),
+        ] {
+            let n = perlin.noise(&p);
+            assert!(n.is_finite());
+            assert!((-1.1..=1.1).contains(&n), "noise({p:?}) = {n} out of range");
+        }
+    }
+
+    #[test]
+    fn noise_texture_marble_value_stays_in_0_1() {
+        let texture = NoiseTexture::new(4.0);
+
+        for p in [
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(12.3, -4.5, 6.7),
+            Point3::new(-8.0, 8.0, -8.0),
+        ] {
+            let color = texture.value(0.0, 0.0, &p);
+            assert!((0.0..=1.0).contains(&color.x));
+            assert!((0.0..=1.0).contains(&color.y));
+            assert!((0.0..=1.0).contains(&color.z));
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use rand::{Rng, RngCore};
+
+/// Returns a random real in `[0, 1)`.
+pub fn random_double() -> f64 {
+    rand::thread_rng().gen::<f64>()
+}
+
+/// Returns a random real in `[min, max)`.
+pub fn random_double_range(min: f64, max: f64) -> f64 {
+    min + (max - min) * random_double()
+}
+
+/// Returns a random real in `[0, 1)` drawn from `rng` instead of the
+/// ambient thread-local generator. Used on the render path, where each
+/// row owns a seeded RNG so the same scene reproduces the same noise
+/// pattern run to run regardless of how rayon schedules the rows.
+pub fn random_double_from(rng: &mut dyn RngCore) -> f64 {
+    rng.gen::<f64>()
+}
+
+/// Returns a random real in `[min, max)` drawn from `rng`; see
+/// `random_double_from`.
+pub fn random_double_range_from(rng: &mut dyn RngCore, min: f64, max: f64) -> f64 {
+    min + (max - min) * random_double_from(rng)
+}
+
+pub fn degrees_to_radians(degrees: f64) -> f64 {
+    degrees * std::f64::consts::PI / 180.0
+}
+
+/// A contiguous range `[min, max]`. Ray `t` bounds and the `AABB` slab test
+/// take plain `f64` pairs, not this type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl Interval {
+    pub const fn new(min: f64, max: f64) -> Self {
+        Interval { min, max }
+    }
+
+    pub const EMPTY: Interval = Interval::new(f64::INFINITY, f64::NEG_INFINITY);
+    pub const UNIVERSE: Interval = Interval::new(f64::NEG_INFINITY, f64::INFINITY);
+
+    pub fn size(&self) -> f64 {
+        self.max - self.min
+    }
+
+    pub fn contains(&self, x: f64) -> bool {
+        self.min <= x && x <= self.max
+    }
+
+    pub fn surrounds(&self, x: f64) -> bool {
+        self.min < x && x < self.max
+    }
+
+    pub fn clamp(&self, x: f64) -> f64 {
+        x.clamp(self.min, self.max)
+    }
+
+    pub fn union(&self, other: &Interval) -> Interval {
+        Interval::new(self.min.min(other.min), self.max.max(other.max))
+    }
+}
+
+impl Default for Interval {
+    fn default() -> Self {
+        Interval::EMPTY
+    }
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use rand::RngCore;
+
+use crate::bvh::AABB;
+use crate::hittable::{Color, HitRecord, Hittable, Ray, Vec3};
+use crate::materials::Material;
+use crate::textures::Texture;
+use crate::utils::random_double_from;
+
+/// A participating medium of constant density (smoke, fog, haze) filling the
+/// interior of a boundary primitive. Rays that enter the boundary scatter
+/// isotropically at a random depth inside it rather than at the boundary
+/// surface itself, or pass through unscattered when that depth lies beyond
+/// the far side of the boundary.
+pub struct ConstantMedium {
+    boundary: Arc<dyn Hittable>,
+    neg_inv_density: f64,
+    phase_function: Arc<Material>,
+}
+
+impl ConstantMedium {
+    pub fn new(boundary: Arc<dyn Hittable>, density: f64, albedo: Color) -> Self {
+        ConstantMedium {
+            boundary,
+            neg_inv_density: -1.0 / density,
+            phase_function: Material::isotropic(Arc::new(crate::textures::SolidColor::new(albedo))),
+        }
+    }
+
+    pub fn new_from_texture(boundary: Arc<dyn Hittable>, density: f64, texture: Arc<dyn Texture>) -> Self {
+        ConstantMedium {
+            boundary,
+            neg_inv_density: -1.0 / density,
+            phase_function: Material::isotropic(texture),
+        }
+    }
+}
+
+impl Hittable for ConstantMedium {
+    fn hit(&self, r: &Ray, t_min: f64, t_max: f64, rng: &mut dyn RngCore) -> Option<HitRecord> {
+        let mut rec1 = self.boundary.hit(r, f64::NEG_INFINITY, f64::INFINITY, rng)?;
+        let mut rec2 = self.boundary.hit(r, rec1.t + 0.0001, f64::INFINITY, rng)?;
+
+        if rec1.t < t_min {
+            rec1.t = t_min;
+        }
+        if rec2.t > t_max {
+            rec2.t = t_max;
+        }
+
+        if rec1.t >= rec2.t {
+            return None;
+        }
+        if rec1.t < 0.0 {
+            rec1.t = 0.0;
+        }
+
+        let ray_length = r.dir.length();
+        let distance_inside_boundary = (rec2.t - rec1.t) * ray_length;
+        let hit_distance = self.neg_inv_density * random_double_from(rng).ln();
+
+        if hit_distance > distance_inside_boundary {
+            return None;
+        }
+
+        let t = rec1.t + hit_distance / ray_length;
+        let p = r.at(t);
+
+        Some(HitRecord {
+            p,
+            // Arbitrary, since a volume has no real surface to shade.
+            normal: Vec3::new(1.0, 0.0, 0.0),
+            mat: self.phase_function.clone(),
+            t,
+            u: 0.0,
+            v: 0.0,
+            front_face: true,
+        })
+    }
+
+    fn bounding_box(&self) -> AABB {
+        self.boundary.bounding_box()
+    }
+}